@@ -58,11 +58,17 @@ macro_rules! always {
 
     ($cond:expr, $fmt:literal $($arg:tt)*) => {{
         let cond = $cond;
-        if cfg!(debug_assertions) || $crate::__FORCE {
-            assert!(cond, $fmt $($arg)*);
-        }
         if !cond {
-            $crate::__log_error!($fmt $($arg)*);
+            let message = ::std::format!($fmt $($arg)*);
+            let context = $crate::__context_suffix();
+            if cfg!(debug_assertions) || $crate::__FORCE {
+                ::std::panic!("{}{}", message, context);
+            }
+            $crate::on_assert_failure(
+                ::std::panic::Location::caller(),
+                ::std::format_args!("{}{}", message, context),
+            );
+            $crate::__log_error!("{}{}", message, context);
         }
         cond
     }}
@@ -89,6 +95,222 @@ macro_rules! never {
     }
 }
 
+/// Asserts that two expressions are always equal and returns the result of the
+/// comparison.
+///
+/// Works like [`always!`], but, like std's `assert_eq!`, captures both operands
+/// and includes their `Debug` representations in the default failure message.
+/// The operands are evaluated exactly once. `format!` style arguments replace
+/// the default message.
+///
+/// If the values are equal does nothing and evaluates to true.
+///
+/// If the values differ:
+/// * panics if `force` feature or `debug_assertions` are enabled,
+/// * logs an error if `log` feature is enabled,
+/// * evaluates to false.
+#[macro_export]
+macro_rules! always_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => $crate::always!(
+                *left == *right,
+                "assertion failed: `(left == right)`\n  left = `{:?}`,\n right = `{:?}`",
+                left, right
+            ),
+        }
+    };
+
+    ($left:expr, $right:expr, $fmt:literal $($arg:tt)*) => {
+        match (&$left, &$right) {
+            (left, right) => $crate::always!(*left == *right, $fmt $($arg)*),
+        }
+    };
+}
+
+/// Asserts that two expressions are always different and returns the result of
+/// the comparison.
+///
+/// The `!=` counterpart of [`always_eq!`].
+#[macro_export]
+macro_rules! always_ne {
+    ($left:expr, $right:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => $crate::always!(
+                *left != *right,
+                "assertion failed: `(left != right)`\n  left = `{:?}`,\n right = `{:?}`",
+                left, right
+            ),
+        }
+    };
+
+    ($left:expr, $right:expr, $fmt:literal $($arg:tt)*) => {
+        match (&$left, &$right) {
+            (left, right) => $crate::always!(*left != *right, $fmt $($arg)*),
+        }
+    };
+}
+
+/// Asserts that two expressions are never equal and returns the result of the
+/// comparison.
+///
+/// The negation of [`always_ne!`]: it panics or logs when the values *are*
+/// equal and evaluates to the result of `left == right`.
+#[macro_export]
+macro_rules! never_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        !$crate::always_ne!($left, $right)
+    };
+
+    ($left:expr, $right:expr, $fmt:literal $($arg:tt)*) => {
+        !$crate::always_ne!($left, $right, $fmt $($arg)*)
+    };
+}
+
+/// Asserts that two expressions are never different and returns the result of
+/// the comparison.
+///
+/// The negation of [`always_eq!`]: it panics or logs when the values *do*
+/// differ and evaluates to the result of `left != right`.
+#[macro_export]
+macro_rules! never_ne {
+    ($left:expr, $right:expr $(,)?) => {
+        !$crate::always_eq!($left, $right)
+    };
+
+    ($left:expr, $right:expr, $fmt:literal $($arg:tt)*) => {
+        !$crate::always_eq!($left, $right, $fmt $($arg)*)
+    };
+}
+
+/// Asserts that an expression always matches a pattern and returns whether it
+/// did.
+///
+/// Works like [`always!`], but tests the value against a pattern with
+/// `matches!` instead of a boolean condition, and includes the value's `Debug`
+/// representation in the default failure message. The expression is evaluated
+/// exactly once. An optional `if` guard and `format!` style arguments are
+/// accepted, mirroring std's unstable `assert_matches!`.
+///
+/// If the value matches does nothing and evaluates to true.
+///
+/// If the value does not match:
+/// * panics if `force` feature or `debug_assertions` are enabled,
+/// * logs an error if `log` feature is enabled,
+/// * evaluates to false.
+#[macro_export]
+macro_rules! always_matches {
+    ($expr:expr, $pat:pat $(if $guard:expr)? $(,)?) => {
+        match $expr {
+            ref value => $crate::always!(
+                matches!(*value, $pat $(if $guard)?),
+                "assertion failed: `{:?}` does not match `{}`",
+                value, stringify!($pat $(if $guard)?)
+            ),
+        }
+    };
+
+    ($expr:expr, $pat:pat $(if $guard:expr)?, $fmt:literal $($arg:tt)*) => {
+        match $expr {
+            ref value => $crate::always!(matches!(*value, $pat $(if $guard)?), $fmt $($arg)*),
+        }
+    };
+}
+
+/// Asserts that a `Result` is always `Ok` and returns the inner value as an
+/// `Option<T>` for recovery.
+///
+/// If the value is `Ok(x)` evaluates to `Some(x)`.
+///
+/// If the value is `Err`:
+/// * panics (printing the `Err` via `Debug`) if `force` feature or
+///   `debug_assertions` are enabled,
+/// * logs an error if `log` feature is enabled,
+/// * evaluates to `None`, so the caller can fall back to a default.
+///
+/// Accepts `format!` style arguments for a custom failure message, which
+/// replaces the default one; the `Err` is then not printed.
+#[macro_export]
+macro_rules! always_ok {
+    ($expr:expr) => {
+        match $expr {
+            ::std::result::Result::Ok(it) => ::std::option::Option::Some(it),
+            ::std::result::Result::Err(err) => {
+                let message = ::std::format!(
+                    "assertion failed: `{}` is `Err`: {:?}",
+                    ::std::stringify!($expr), err
+                );
+                let context = $crate::__context_suffix();
+                if cfg!(debug_assertions) || $crate::__FORCE {
+                    ::std::panic!("{}{}", message, context);
+                }
+                $crate::on_assert_failure(
+                    ::std::panic::Location::caller(),
+                    ::std::format_args!("{}{}", message, context),
+                );
+                $crate::__log_error!("{}{}", message, context);
+                ::std::option::Option::None
+            }
+        }
+    };
+
+    ($expr:expr, $fmt:literal $($arg:tt)*) => {
+        match $expr {
+            ::std::result::Result::Ok(it) => ::std::option::Option::Some(it),
+            ::std::result::Result::Err(_err) => {
+                let message = ::std::format!($fmt $($arg)*);
+                let context = $crate::__context_suffix();
+                if cfg!(debug_assertions) || $crate::__FORCE {
+                    ::std::panic!("{}{}", message, context);
+                }
+                $crate::on_assert_failure(
+                    ::std::panic::Location::caller(),
+                    ::std::format_args!("{}{}", message, context),
+                );
+                $crate::__log_error!("{}{}", message, context);
+                ::std::option::Option::None
+            }
+        }
+    };
+}
+
+/// Asserts that an `Option` is always `Some` and returns the inner value as an
+/// `Option<T>` for recovery.
+///
+/// If the value is `Some(x)` evaluates to `Some(x)`.
+///
+/// If the value is `None`:
+/// * panics if `force` feature or `debug_assertions` are enabled,
+/// * logs an error if `log` feature is enabled,
+/// * evaluates to `None`, so the caller can fall back to a default.
+///
+/// Accepts `format!` style arguments for a custom failure message.
+#[macro_export]
+macro_rules! always_some {
+    ($expr:expr) => {
+        $crate::always_some!($expr, "assertion failed: `{}` is `None`", ::std::stringify!($expr))
+    };
+
+    ($expr:expr, $fmt:literal $($arg:tt)*) => {
+        match $expr {
+            ::std::option::Option::Some(it) => ::std::option::Option::Some(it),
+            ::std::option::Option::None => {
+                let message = ::std::format!($fmt $($arg)*);
+                let context = $crate::__context_suffix();
+                if cfg!(debug_assertions) || $crate::__FORCE {
+                    ::std::panic!("{}{}", message, context);
+                }
+                $crate::on_assert_failure(
+                    ::std::panic::Location::caller(),
+                    ::std::format_args!("{}{}", message, context),
+                );
+                $crate::__log_error!("{}{}", message, context);
+                ::std::option::Option::None
+            }
+        }
+    };
+}
+
 #[cfg(feature = "log")]
 #[doc(hidden)]
 pub use log::error as __log_error;
@@ -101,4 +323,101 @@ macro_rules! __log_error {
 }
 
 #[doc(hidden)]
-pub const __FORCE: bool = cfg!(feature = "force");
\ No newline at end of file
+pub const __FORCE: bool = cfg!(feature = "force");
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// The signature of an assertion-failure hook, see [`set_assert_hook`].
+pub type AssertHook = fn(&std::panic::Location<'static>, std::fmt::Arguments<'_>);
+
+static ASSERT_HOOK: AtomicUsize = AtomicUsize::new(0);
+
+/// Registers a process-wide hook that fires whenever an `always!`/`never!`
+/// assertion fails on the recoverable, non-panicking path.
+///
+/// Long-running services can use this to report the failure to telemetry,
+/// increment a metric, or capture a backtrace without crashing. The hook is
+/// given the [`Location`](std::panic::Location) of the assertion and the
+/// formatted failure message.
+///
+/// The hook is *not* called when the assertion panics (under `debug_assertions`
+/// or the `force` feature); use a panic hook for that case.
+pub fn set_assert_hook(hook: AssertHook) {
+    ASSERT_HOOK.store(hook as usize, Ordering::SeqCst);
+}
+
+/// A thread-local stack of human-readable context descriptions that is appended
+/// to the message of any `always!`/`never!` assertion that fails on the current
+/// thread.
+///
+/// This lets a server that multiplexes many requests know *which* operation
+/// tripped a recoverable assertion — push the request id (or the name of the
+/// file being processed) for the duration of the operation, and it shows up in
+/// both the panicked and the logged message.
+///
+/// ```ignore
+/// use always_assert::{always, panic_context};
+///
+/// let _guard = panic_context::push(format!("request {}", request_id));
+/// always!(invariant_holds());
+/// ```
+pub mod panic_context {
+    use std::cell::RefCell;
+
+    thread_local! {
+        static CONTEXT: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+    }
+
+    /// Pushes `description` onto the current thread's context stack.
+    ///
+    /// The entry is popped when the returned [`ContextGuard`] is dropped, so the
+    /// stack mirrors the current call stack.
+    #[must_use]
+    pub fn push(description: String) -> ContextGuard {
+        CONTEXT.with(|it| it.borrow_mut().push(description));
+        ContextGuard { _priv: () }
+    }
+
+    /// Pops the context entry pushed by [`push`] when dropped.
+    pub struct ContextGuard {
+        _priv: (),
+    }
+
+    impl Drop for ContextGuard {
+        fn drop(&mut self) {
+            CONTEXT.with(|it| {
+                it.borrow_mut().pop();
+            });
+        }
+    }
+
+    pub(crate) fn with_current<T>(f: impl FnOnce(&[String]) -> T) -> T {
+        CONTEXT.with(|it| f(&it.borrow()))
+    }
+}
+
+#[doc(hidden)]
+pub fn __context_suffix() -> String {
+    panic_context::with_current(|stack| {
+        let mut suffix = String::new();
+        for frame in stack {
+            suffix.push_str("\n  context: ");
+            suffix.push_str(frame);
+        }
+        suffix
+    })
+}
+
+#[doc(hidden)]
+pub fn on_assert_failure(
+    location: &std::panic::Location<'static>,
+    args: std::fmt::Arguments<'_>,
+) {
+    let hook = ASSERT_HOOK.load(Ordering::SeqCst);
+    if hook != 0 {
+        // SAFETY: `hook` is either zero (handled above) or a function pointer
+        // stored by `set_assert_hook`, which only ever writes `AssertHook` values.
+        let hook: AssertHook = unsafe { std::mem::transmute(hook) };
+        hook(location, args);
+    }
+}
\ No newline at end of file
@@ -1,6 +1,8 @@
 #[cfg(any(debug_assertions, feature = "force"))]
 mod armed {
-    use always_assert::{always, never};
+    use always_assert::{
+        always, always_eq, always_matches, always_ne, always_ok, always_some, never, never_eq,
+    };
 
     #[test]
     #[should_panic = "assertion failed: 2 + 2 == 5"]
@@ -49,11 +51,89 @@ mod armed {
             loop {}
         }
     }
+
+    #[test]
+    #[should_panic = "left = `4`,\n right = `5`"]
+    fn eq1() {
+        if always_eq!(2 + 2, 5) {
+            loop {}
+        }
+    }
+
+    #[test]
+    #[should_panic = "custom 92"]
+    fn eq2() {
+        if always_eq!(2 + 2, 5, "custom {}", 92) {
+            loop {}
+        }
+    }
+
+    #[test]
+    #[should_panic = "left = `4`,\n right = `4`"]
+    fn ne1() {
+        if always_ne!(2 + 2, 4) {
+            loop {}
+        }
+    }
+
+    #[test]
+    #[should_panic = "left = `4`,\n right = `4`"]
+    fn never_eq1() {
+        if never_eq!(2 + 2, 4) {
+            loop {}
+        }
+    }
+
+    #[test]
+    #[should_panic = "`None` does not match `Some(_)`"]
+    fn matches1() {
+        let x: Option<i32> = None;
+        if always_matches!(x, Some(_)) {
+            loop {}
+        }
+    }
+
+    #[test]
+    #[should_panic = "custom 92"]
+    fn matches2() {
+        if always_matches!(Some(1), Some(x) if x > 1, "custom {}", 92) {
+            loop {}
+        }
+    }
+
+    #[test]
+    #[should_panic = "is `Err`: \"boom\""]
+    fn ok1() {
+        let res: Result<i32, &str> = Err("boom");
+        if let Some(_) = always_ok!(res) {
+            loop {}
+        }
+    }
+
+    #[test]
+    #[should_panic = "custom 92"]
+    fn some1() {
+        let opt: Option<i32> = None;
+        if let Some(_) = always_some!(opt, "custom {}", 92) {
+            loop {}
+        }
+    }
+
+    #[test]
+    #[should_panic = "boom\n  context: request 42"]
+    fn context1() {
+        let _guard = always_assert::panic_context::push("request 42".to_string());
+        if always!(false, "boom") {
+            loop {}
+        }
+    }
 }
 
 #[cfg(all(not(debug_assertions), not(feature = "force")))]
 mod disarmed {
-    use always_assert::{always, never};
+    use always_assert::{
+        always, always_eq, always_matches, always_ne, always_ok, always_some, never, never_eq,
+    };
 
     #[test]
     fn syntax1() {
@@ -64,4 +144,46 @@ mod disarmed {
     fn syntax2() {
         assert!(never!(true));
     }
+
+    #[test]
+    fn eq1() {
+        assert!(!always_eq!(2 + 2, 5));
+        assert!(always_ne!(2 + 2, 5));
+        assert!(!never_eq!(2 + 2, 5));
+    }
+
+    #[test]
+    fn matches1() {
+        let x: Option<i32> = None;
+        assert!(!always_matches!(x, Some(_)));
+        assert!(always_matches!(x, None));
+    }
+
+    #[test]
+    fn unwrap1() {
+        let res: Result<i32, &str> = Err("boom");
+        assert_eq!(always_ok!(res), None);
+        assert_eq!(always_ok!(Ok::<i32, &str>(1)), Some(1));
+
+        let opt: Option<i32> = None;
+        assert_eq!(always_some!(opt), None);
+        assert_eq!(always_some!(Some(1)), Some(1));
+    }
+
+    #[test]
+    fn hook() {
+        use std::cell::Cell;
+
+        // The hook is process-wide and the other tests here fail assertions on
+        // their own threads, so only hits from this thread are counted.
+        thread_local! {
+            static HITS: Cell<usize> = const { Cell::new(0) };
+        }
+        always_assert::set_assert_hook(|_loc, _args| {
+            HITS.with(|it| it.set(it.get() + 1));
+        });
+
+        assert!(!always!(false));
+        assert_eq!(HITS.with(|it| it.get()), 1);
+    }
 }